@@ -53,16 +53,48 @@ extern crate stringly_typed_derive;
 #[doc(hidden)]
 pub use stringly_typed_derive::*;
 
+#[cfg(feature = "bigint")]
+extern crate bigdecimal;
+#[cfg(feature = "bigint")]
+extern crate num_bigint;
+
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "std")]
+pub use path::{parse_path, PathError, PathSegment};
+
 pub const DOUBLE_TYPE: &'static str = "double";
 pub const INTEGER_TYPE: &'static str = "integer";
 pub const STRING_TYPE: &'static str = "string";
+pub const ARRAY_TYPE: &'static str = "array";
+pub const MAP_TYPE: &'static str = "map";
+pub const INDEX_TYPE: &'static str = "index";
+pub const BOOLEAN_TYPE: &'static str = "boolean";
+#[cfg(feature = "bigint")]
+pub const BIG_INTEGER_TYPE: &'static str = "big_integer";
+#[cfg(feature = "bigint")]
+pub const BIG_DECIMAL_TYPE: &'static str = "big_decimal";
 
 /// The whole point.
 pub trait StringlyTyped {
+    #[cfg(feature = "std")]
+    fn get(&self, key: &str) -> Result<Value, UpdateError> {
+        let path = parse_path(key).map_err(UpdateError::InvalidPath)?;
+        self.get_value(path.iter().map(PathSegment::to_key))
+    }
+
+    #[cfg(not(feature = "std"))]
     fn get(&self, key: &str) -> Result<Value, UpdateError> {
         self.get_value(key.split("."))
     }
 
+    #[cfg(feature = "std")]
+    fn set(&mut self, key: &str, value: Value) -> Result<(), UpdateError> {
+        let path = parse_path(key).map_err(UpdateError::InvalidPath)?;
+        self.set_value(path.iter().map(PathSegment::to_key), value)
+    }
+
+    #[cfg(not(feature = "std"))]
     fn set(&mut self, key: &str, value: Value) -> Result<(), UpdateError> {
         self.set_value(key.split("."), value)
     }
@@ -78,6 +110,41 @@ pub trait StringlyTyped {
         S: AsRef<str>;
 
     fn data_type(&self) -> &'static str;
+
+    /// Like [`set_value`](StringlyTyped::set_value), but first tries to
+    /// widen `value` to fit this field instead of going straight to a
+    /// [`UpdateError::TypeError`]: `integer` widens to `double` when it
+    /// round-trips through `f64` exactly, `integer`/`double` convert to
+    /// `string` with `to_string`, and a `string` that fully parses as this
+    /// field's numeric type is accepted.
+    /// Falls back to the strict behaviour of `set_value` by default.
+    fn set_value_coerced<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+    where
+        K: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.set_value(keys, value)
+    }
+
+    #[cfg(feature = "std")]
+    fn set_coerced(&mut self, key: &str, value: Value) -> Result<(), UpdateError> {
+        let path = parse_path(key).map_err(UpdateError::InvalidPath)?;
+        self.set_value_coerced(path.iter().map(PathSegment::to_key), value)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn set_coerced(&mut self, key: &str, value: Value) -> Result<(), UpdateError> {
+        self.set_value_coerced(key.split("."), value)
+    }
+
+    /// Every addressable leaf path in this value, together with its
+    /// `data_type`, e.g. `("inner.x", "double")`. Structs, enums and
+    /// collections recurse and prefix with their own keys; for a leaf
+    /// value (the default impl used by primitives) this is just itself.
+    #[cfg(feature = "std")]
+    fn paths(&self) -> Vec<(String, &'static str)> {
+        vec![(String::new(), self.data_type())]
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -89,19 +156,37 @@ pub enum UpdateError {
     TooManyKeys {
         elements_remaning: usize,
     },
+    NotEnoughKeys,
     UnknownField {
         valid_fields: &'static [&'static str],
+        /// The closest valid field, if any was close enough to be worth
+        /// suggesting. See [`suggest_field`].
+        suggestion: Option<&'static str>,
     },
     CantSerialize { data_type: &'static str },
+    /// A sequence (`Vec`, array, ...) was indexed past its end.
+    IndexOutOfBounds { len: usize },
+    /// A map was indexed by a key it doesn't contain.
+    MissingKey,
+    /// The path expression passed to `get`/`set` couldn't be parsed.
+    #[cfg(feature = "std")]
+    InvalidPath(PathError),
 }
 
 /// A dynamically typed value.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
+    Boolean(bool),
     Integer(i64),
     Double(f64),
     #[cfg(feature = "std")]
     String(String),
+    /// An arbitrary-precision integer, for values too big for `i64`.
+    #[cfg(feature = "bigint")]
+    BigInt(num_bigint::BigInt),
+    /// An arbitrary-precision decimal, for values too precise for `f64`.
+    #[cfg(feature = "bigint")]
+    BigDecimal(bigdecimal::BigDecimal),
     #[doc(hidden)]
     __NonExhaustive,
 }
@@ -109,15 +194,26 @@ pub enum Value {
 impl Value {
     pub fn data_type(&self) -> &'static str {
         match *self {
+            Value::Boolean(_) => BOOLEAN_TYPE,
             Value::Integer(_) => INTEGER_TYPE,
             Value::Double(_) => DOUBLE_TYPE,
             #[cfg(feature = "std")]
             Value::String(_) => STRING_TYPE,
+            #[cfg(feature = "bigint")]
+            Value::BigInt(_) => BIG_INTEGER_TYPE,
+            #[cfg(feature = "bigint")]
+            Value::BigDecimal(_) => BIG_DECIMAL_TYPE,
             Value::__NonExhaustive => unreachable!(),
         }
     }
 }
 
+impl From<bool> for Value {
+    fn from(other: bool) -> Value {
+        Value::Boolean(other)
+    }
+}
+
 impl From<i64> for Value {
     fn from(other: i64) -> Value {
         Value::Integer(other)
@@ -144,16 +240,34 @@ impl<'a> From<&'a str> for Value {
     }
 }
 
+#[cfg(feature = "bigint")]
+impl From<num_bigint::BigInt> for Value {
+    fn from(other: num_bigint::BigInt) -> Value {
+        Value::BigInt(other)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<bigdecimal::BigDecimal> for Value {
+    fn from(other: bigdecimal::BigDecimal) -> Value {
+        Value::BigDecimal(other)
+    }
+}
+
 macro_rules! impl_primitive_type {
     ($(#[$attr:meta])* $type:ty, $variant:ident, $data_type:expr) => {
+        impl_primitive_type!($(#[$attr])* $type, $variant, $data_type, coerce: |this| {});
+    };
+
+    ($(#[$attr:meta])* $type:ty, $variant:ident, $data_type:expr, coerce: |$this:ident| { $($coerce:tt)* }) => {
         $(#[$attr])*
         impl StringlyTyped for $type {
             fn set_value<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
             where K: IntoIterator<Item = S>,
-                  S: AsRef<str> 
+                  S: AsRef<str>
             {
                 let mut keys = keys.into_iter();
-                
+
                 if let Some(_) = keys.next() {
                     let elements_remaning = keys.count() + 1;
                     return Err(UpdateError::TooManyKeys { elements_remaning });
@@ -165,8 +279,8 @@ macro_rules! impl_primitive_type {
                         Ok(())
                     }
                     _ => {
-                        let e = UpdateError::TypeError { 
-                            expected: self.data_type(), 
+                        let e = UpdateError::TypeError {
+                            expected: self.data_type(),
                             found: value.data_type(),
                         };
                         Err(e)
@@ -179,7 +293,7 @@ macro_rules! impl_primitive_type {
                 S: AsRef<str>,
             {
                 let mut keys = keys.into_iter();
-                
+
                 if let Some(_) = keys.next() {
                     let elements_remaning = keys.count() + 1;
                     return Err(UpdateError::TooManyKeys { elements_remaning });
@@ -191,13 +305,553 @@ macro_rules! impl_primitive_type {
             fn data_type(&self) -> &'static str {
                 $data_type
             }
+
+            fn set_value_coerced<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+            where K: IntoIterator<Item = S>,
+                  S: AsRef<str>,
+            {
+                let mut keys = keys.into_iter();
+
+                if let Some(_) = keys.next() {
+                    let elements_remaning = keys.count() + 1;
+                    return Err(UpdateError::TooManyKeys { elements_remaning });
+                }
+
+                let $this = self;
+                match value {
+                    Value::$variant(v) => {
+                        *$this = v;
+                        Ok(())
+                    }
+                    $($coerce)*
+                    other => Err(UpdateError::TypeError {
+                        expected: $data_type,
+                        found: other.data_type(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_primitive_type!(i64, Integer, INTEGER_TYPE, coerce: |this| {
+    #[cfg(feature = "std")]
+    Value::String(ref s) => match s.parse::<i64>() {
+        Ok(v) => { *this = v; Ok(()) }
+        Err(_) => Err(UpdateError::TypeError { expected: INTEGER_TYPE, found: STRING_TYPE }),
+    },
+});
+impl_primitive_type!(f64, Double, DOUBLE_TYPE, coerce: |this| {
+    Value::Integer(v) if v as f64 as i64 == v => { *this = v as f64; Ok(()) },
+    Value::Integer(_) => Err(UpdateError::TypeError { expected: DOUBLE_TYPE, found: INTEGER_TYPE }),
+    #[cfg(feature = "std")]
+    Value::String(ref s) => match s.parse::<f64>() {
+        Ok(v) => { *this = v; Ok(()) }
+        Err(_) => Err(UpdateError::TypeError { expected: DOUBLE_TYPE, found: STRING_TYPE }),
+    },
+});
+impl_primitive_type!(bool, Boolean, BOOLEAN_TYPE);
+impl_primitive_type!(#[cfg(feature = "std")] String, String, STRING_TYPE, coerce: |this| {
+    Value::Integer(v) => { *this = v.to_string(); Ok(()) },
+    Value::Double(v) => { *this = v.to_string(); Ok(()) },
+});
+
+/// Implement `StringlyTyped` for a fixed-width unsigned integer by funnelling
+/// it through the canonical `Value::Integer(i64)`, range-checking on `set`.
+macro_rules! impl_unsigned_integer_type {
+    ($type:ty, $data_type:expr) => {
+        impl StringlyTyped for $type {
+            fn set_value<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+            where
+                K: IntoIterator<Item = S>,
+                S: AsRef<str>,
+            {
+                let mut keys = keys.into_iter();
+
+                if let Some(_) = keys.next() {
+                    let elements_remaning = keys.count() + 1;
+                    return Err(UpdateError::TooManyKeys { elements_remaning });
+                }
+
+                match value {
+                    Value::Integer(v) if v >= 0 && v as u64 <= <$type>::max_value() as u64 => {
+                        *self = v as $type;
+                        Ok(())
+                    }
+                    Value::Integer(_) => Err(UpdateError::TypeError {
+                        expected: $data_type,
+                        found: INTEGER_TYPE,
+                    }),
+                    _ => Err(UpdateError::TypeError {
+                        expected: $data_type,
+                        found: value.data_type(),
+                    }),
+                }
+            }
+
+            fn get_value<K, S>(&self, keys: K) -> Result<Value, UpdateError>
+            where
+                K: IntoIterator<Item = S>,
+                S: AsRef<str>,
+            {
+                let mut keys = keys.into_iter();
+
+                if let Some(_) = keys.next() {
+                    let elements_remaning = keys.count() + 1;
+                    return Err(UpdateError::TooManyKeys { elements_remaning });
+                }
+
+                if *self as u64 > i64::max_value() as u64 {
+                    return Err(UpdateError::TypeError {
+                        expected: INTEGER_TYPE,
+                        found: $data_type,
+                    });
+                }
+
+                Ok(Value::Integer(*self as i64))
+            }
+
+            fn data_type(&self) -> &'static str {
+                $data_type
+            }
+
+            fn set_value_coerced<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+            where
+                K: IntoIterator<Item = S>,
+                S: AsRef<str>,
+            {
+                let mut keys = keys.into_iter();
+
+                if let Some(_) = keys.next() {
+                    let elements_remaning = keys.count() + 1;
+                    return Err(UpdateError::TooManyKeys { elements_remaning });
+                }
+
+                match value {
+                    Value::Integer(v) if v >= 0 && v as u64 <= <$type>::max_value() as u64 => {
+                        *self = v as $type;
+                        Ok(())
+                    }
+                    #[cfg(feature = "std")]
+                    Value::String(ref s) => match s.parse::<$type>() {
+                        Ok(v) => {
+                            *self = v;
+                            Ok(())
+                        }
+                        Err(_) => Err(UpdateError::TypeError {
+                            expected: $data_type,
+                            found: STRING_TYPE,
+                        }),
+                    },
+                    other => Err(UpdateError::TypeError {
+                        expected: $data_type,
+                        found: other.data_type(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+/// Implement `StringlyTyped` for a fixed-width signed integer narrower than
+/// `i64`, range-checking on `set`.
+macro_rules! impl_signed_integer_type {
+    ($type:ty, $data_type:expr) => {
+        impl StringlyTyped for $type {
+            fn set_value<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+            where
+                K: IntoIterator<Item = S>,
+                S: AsRef<str>,
+            {
+                let mut keys = keys.into_iter();
+
+                if let Some(_) = keys.next() {
+                    let elements_remaning = keys.count() + 1;
+                    return Err(UpdateError::TooManyKeys { elements_remaning });
+                }
+
+                match value {
+                    Value::Integer(v)
+                        if v >= <$type>::min_value() as i64 && v <= <$type>::max_value() as i64 =>
+                    {
+                        *self = v as $type;
+                        Ok(())
+                    }
+                    Value::Integer(_) => Err(UpdateError::TypeError {
+                        expected: $data_type,
+                        found: INTEGER_TYPE,
+                    }),
+                    _ => Err(UpdateError::TypeError {
+                        expected: $data_type,
+                        found: value.data_type(),
+                    }),
+                }
+            }
+
+            fn get_value<K, S>(&self, keys: K) -> Result<Value, UpdateError>
+            where
+                K: IntoIterator<Item = S>,
+                S: AsRef<str>,
+            {
+                let mut keys = keys.into_iter();
+
+                if let Some(_) = keys.next() {
+                    let elements_remaning = keys.count() + 1;
+                    return Err(UpdateError::TooManyKeys { elements_remaning });
+                }
+
+                Ok(Value::Integer(*self as i64))
+            }
+
+            fn data_type(&self) -> &'static str {
+                $data_type
+            }
+
+            fn set_value_coerced<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+            where
+                K: IntoIterator<Item = S>,
+                S: AsRef<str>,
+            {
+                let mut keys = keys.into_iter();
+
+                if let Some(_) = keys.next() {
+                    let elements_remaning = keys.count() + 1;
+                    return Err(UpdateError::TooManyKeys { elements_remaning });
+                }
+
+                match value {
+                    Value::Integer(v)
+                        if v >= <$type>::min_value() as i64 && v <= <$type>::max_value() as i64 =>
+                    {
+                        *self = v as $type;
+                        Ok(())
+                    }
+                    #[cfg(feature = "std")]
+                    Value::String(ref s) => match s.parse::<$type>() {
+                        Ok(v) => {
+                            *self = v;
+                            Ok(())
+                        }
+                        Err(_) => Err(UpdateError::TypeError {
+                            expected: $data_type,
+                            found: STRING_TYPE,
+                        }),
+                    },
+                    other => Err(UpdateError::TypeError {
+                        expected: $data_type,
+                        found: other.data_type(),
+                    }),
+                }
+            }
         }
     };
 }
 
-impl_primitive_type!(i64, Integer, INTEGER_TYPE);
-impl_primitive_type!(f64, Double, DOUBLE_TYPE);
-impl_primitive_type!(#[cfg(feature = "std")] String, String, STRING_TYPE);
+pub const U8_TYPE: &'static str = "u8";
+pub const U16_TYPE: &'static str = "u16";
+pub const U32_TYPE: &'static str = "u32";
+pub const U64_TYPE: &'static str = "u64";
+pub const I8_TYPE: &'static str = "i8";
+pub const I16_TYPE: &'static str = "i16";
+pub const I32_TYPE: &'static str = "i32";
+pub const F32_TYPE: &'static str = "f32";
+
+impl_unsigned_integer_type!(u8, U8_TYPE);
+impl_unsigned_integer_type!(u16, U16_TYPE);
+impl_unsigned_integer_type!(u32, U32_TYPE);
+impl_unsigned_integer_type!(u64, U64_TYPE);
+impl_signed_integer_type!(i8, I8_TYPE);
+impl_signed_integer_type!(i16, I16_TYPE);
+impl_signed_integer_type!(i32, I32_TYPE);
+
+impl StringlyTyped for f32 {
+    fn set_value<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+    where
+        K: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut keys = keys.into_iter();
+
+        if let Some(_) = keys.next() {
+            let elements_remaning = keys.count() + 1;
+            return Err(UpdateError::TooManyKeys { elements_remaning });
+        }
+
+        match value {
+            Value::Double(v) => {
+                *self = v as f32;
+                Ok(())
+            }
+            _ => Err(UpdateError::TypeError {
+                expected: F32_TYPE,
+                found: value.data_type(),
+            }),
+        }
+    }
+
+    fn get_value<K, S>(&self, keys: K) -> Result<Value, UpdateError>
+    where
+        K: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut keys = keys.into_iter();
+
+        if let Some(_) = keys.next() {
+            let elements_remaning = keys.count() + 1;
+            return Err(UpdateError::TooManyKeys { elements_remaning });
+        }
+
+        Ok(Value::Double(*self as f64))
+    }
+
+    fn data_type(&self) -> &'static str {
+        F32_TYPE
+    }
+
+    fn set_value_coerced<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+    where
+        K: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut keys = keys.into_iter();
+
+        if let Some(_) = keys.next() {
+            let elements_remaning = keys.count() + 1;
+            return Err(UpdateError::TooManyKeys { elements_remaning });
+        }
+
+        match value {
+            Value::Double(v) => {
+                *self = v as f32;
+                Ok(())
+            }
+            Value::Integer(v) if v as f64 as i64 == v => {
+                *self = v as f32;
+                Ok(())
+            }
+            Value::Integer(_) => Err(UpdateError::TypeError {
+                expected: F32_TYPE,
+                found: INTEGER_TYPE,
+            }),
+            #[cfg(feature = "std")]
+            Value::String(ref s) => match s.parse::<f32>() {
+                Ok(v) => {
+                    *self = v;
+                    Ok(())
+                }
+                Err(_) => Err(UpdateError::TypeError {
+                    expected: F32_TYPE,
+                    found: STRING_TYPE,
+                }),
+            },
+            other => Err(UpdateError::TypeError {
+                expected: F32_TYPE,
+                found: other.data_type(),
+            }),
+        }
+    }
+}
+
+/// The classic Levenshtein edit distance: the cheapest sequence of
+/// single-character inserts, deletes, and substitutions (each cost `1`)
+/// that turns `a` into `b`.
+#[cfg(feature = "std")]
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, &a_char) in a.iter().enumerate() {
+        let mut previous_diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, &b_char) in b.iter().enumerate() {
+            let previous_above = row[j + 1];
+
+            row[j + 1] = if a_char == b_char {
+                previous_diagonal
+            } else {
+                1 + previous_diagonal.min(previous_above).min(row[j])
+            };
+
+            previous_diagonal = previous_above;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Find the candidate closest to `query` by [`levenshtein_distance`], as long
+/// as it's close enough to be worth suggesting (distance no more than
+/// `max(1, candidate.len() / 3)`, so unrelated names don't produce noise).
+#[cfg(feature = "std")]
+pub fn suggest_field(query: &str, candidates: &'static [&'static str]) -> Option<&'static str> {
+    candidates
+        .iter()
+        .map(|&candidate| (candidate, levenshtein_distance(query, candidate)))
+        .min_by_key(|&(_, distance)| distance)
+        .filter(|&(candidate, distance)| distance <= 1.max(candidate.len() / 3))
+        .map(|(candidate, _)| candidate)
+}
+
+/// Parse a path segment as a sequence index, the way `Vec`/array indexing does.
+fn parse_index(segment: &str) -> Result<usize, UpdateError> {
+    segment.parse().map_err(|_| UpdateError::TypeError {
+        found: STRING_TYPE,
+        expected: INDEX_TYPE,
+    })
+}
+
+macro_rules! impl_sequence_type {
+    ($type:ty) => {
+        fn set_value<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+        where
+            K: IntoIterator<Item = S>,
+            S: AsRef<str>,
+        {
+            let mut keys = keys.into_iter();
+            let index_key = keys.next().ok_or(UpdateError::NotEnoughKeys)?;
+            let index = parse_index(index_key.as_ref())?;
+            let len = self.len();
+
+            self.get_mut(index)
+                .ok_or(UpdateError::IndexOutOfBounds { len })?
+                .set_value(keys, value)
+        }
+
+        fn get_value<K, S>(&self, keys: K) -> Result<Value, UpdateError>
+        where
+            K: IntoIterator<Item = S>,
+            S: AsRef<str>,
+        {
+            let mut keys = keys.into_iter();
+            let index_key = keys.next().ok_or(UpdateError::NotEnoughKeys)?;
+            let index = parse_index(index_key.as_ref())?;
+            let len = self.len();
+
+            self[..]
+                .get(index)
+                .ok_or(UpdateError::IndexOutOfBounds { len })?
+                .get_value(keys)
+        }
+
+        fn data_type(&self) -> &'static str {
+            ARRAY_TYPE
+        }
+
+        #[cfg(feature = "std")]
+        fn paths(&self) -> Vec<(String, &'static str)> {
+            let mut result = Vec::new();
+
+            for (index, item) in self.iter().enumerate() {
+                for (sub_path, data_type) in item.paths() {
+                    let full_path = if sub_path.is_empty() {
+                        index.to_string()
+                    } else {
+                        format!("{}.{}", index, sub_path)
+                    };
+                    result.push((full_path, data_type));
+                }
+            }
+
+            result
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl<T> StringlyTyped for Vec<T>
+where
+    T: StringlyTyped,
+{
+    impl_sequence_type!(Vec<T>);
+}
+
+macro_rules! impl_array_type {
+    ($($size:expr),* $(,)*) => {
+        $(
+            impl<T> StringlyTyped for [T; $size]
+            where
+                T: StringlyTyped,
+            {
+                impl_sequence_type!([T; $size]);
+            }
+        )*
+    };
+}
+
+impl_array_type!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32,
+);
+
+macro_rules! impl_map_type {
+    ($type:ident) => {
+        fn set_value<K, S>(&mut self, keys: K, value: Value) -> Result<(), UpdateError>
+        where
+            K: IntoIterator<Item = S>,
+            S: AsRef<str>,
+        {
+            let mut keys = keys.into_iter();
+            let map_key = keys.next().ok_or(UpdateError::NotEnoughKeys)?;
+
+            self.entry(map_key.as_ref().to_string())
+                .or_insert_with(T::default)
+                .set_value(keys, value)
+        }
+
+        fn get_value<K, S>(&self, keys: K) -> Result<Value, UpdateError>
+        where
+            K: IntoIterator<Item = S>,
+            S: AsRef<str>,
+        {
+            let mut keys = keys.into_iter();
+            let map_key = keys.next().ok_or(UpdateError::NotEnoughKeys)?;
+
+            self.get(map_key.as_ref())
+                .ok_or(UpdateError::MissingKey)?
+                .get_value(keys)
+        }
+
+        fn data_type(&self) -> &'static str {
+            MAP_TYPE
+        }
+
+        fn paths(&self) -> Vec<(String, &'static str)> {
+            let mut result = Vec::new();
+
+            for (key, item) in self.iter() {
+                for (sub_path, data_type) in item.paths() {
+                    let full_path = if sub_path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", key, sub_path)
+                    };
+                    result.push((full_path, data_type));
+                }
+            }
+
+            result
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl<T> StringlyTyped for ::std::collections::HashMap<String, T>
+where
+    T: StringlyTyped + Default,
+{
+    impl_map_type!(HashMap);
+}
+
+#[cfg(feature = "std")]
+impl<T> StringlyTyped for ::std::collections::BTreeMap<String, T>
+where
+    T: StringlyTyped + Default,
+{
+    impl_map_type!(BTreeMap);
+}
 
 #[cfg(test)]
 mod tests {
@@ -295,4 +949,234 @@ mod tests {
         let got = n.set_value(key, Value::Integer(7)).unwrap_err();
         assert_eq!(got, should_be);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn index_into_a_vec() {
+        let mut numbers: Vec<i64> = vec![1, 2, 3];
+
+        let got = numbers.get_value("1".split(".")).unwrap();
+        assert_eq!(got, Value::from(2));
+
+        numbers.set_value("1".split("."), Value::from(42)).unwrap();
+        assert_eq!(numbers, vec![1, 42, 3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn vec_detects_out_of_bounds_index() {
+        let numbers: Vec<i64> = vec![1, 2, 3];
+
+        let got = numbers.get_value("10".split(".")).unwrap_err();
+        assert_eq!(got, UpdateError::IndexOutOfBounds { len: 3 });
+    }
+
+    #[test]
+    fn index_into_an_array() {
+        let mut numbers: [i64; 3] = [1, 2, 3];
+
+        numbers.set_value("2".split("."), Value::from(99)).unwrap();
+        assert_eq!(numbers, [1, 2, 99]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn index_into_a_map() {
+        use std::collections::HashMap;
+
+        let mut map: HashMap<String, i64> = HashMap::new();
+        map.insert(String::from("answer"), 41);
+
+        let got = map.get_value("answer".split(".")).unwrap();
+        assert_eq!(got, Value::from(41));
+
+        map.set_value("answer".split("."), Value::from(42)).unwrap();
+        assert_eq!(map.get("answer"), Some(&42));
+
+        // Setting a missing key inserts a fresh default before updating it.
+        map.set_value("new".split("."), Value::from(7)).unwrap();
+        assert_eq!(map.get("new"), Some(&7));
+
+        let got = map.get_value("missing".split(".")).unwrap_err();
+        assert_eq!(got, UpdateError::MissingKey);
+    }
+
+    #[test]
+    fn update_a_boolean() {
+        let empty = iter::empty::<&str>();
+
+        let mut flag = false;
+        flag.set_value(empty.clone(), Value::Boolean(true)).unwrap();
+        assert_eq!(flag, true);
+
+        let got = flag.get_value(empty.clone()).unwrap();
+        assert_eq!(got, Value::from(true));
+    }
+
+    #[test]
+    fn narrow_integers_range_check_on_set() {
+        let empty = iter::empty::<&str>();
+
+        let mut byte: u8 = 0;
+        byte.set_value(empty.clone(), Value::Integer(200)).unwrap();
+        assert_eq!(byte, 200);
+
+        let got = byte
+            .set_value(empty.clone(), Value::Integer(1000))
+            .unwrap_err();
+        assert_eq!(
+            got,
+            UpdateError::TypeError {
+                expected: U8_TYPE,
+                found: INTEGER_TYPE,
+            }
+        );
+
+        let got = byte
+            .set_value(empty.clone(), Value::Integer(-1))
+            .unwrap_err();
+        assert_eq!(
+            got,
+            UpdateError::TypeError {
+                expected: U8_TYPE,
+                found: INTEGER_TYPE,
+            }
+        );
+    }
+
+    #[test]
+    fn set_value_is_strict_by_default() {
+        let empty = iter::empty::<&str>();
+
+        let mut integer: i64 = 42;
+        let got = integer
+            .set_value(empty.clone(), Value::Double(7.0))
+            .unwrap_err();
+        assert_eq!(
+            got,
+            UpdateError::TypeError {
+                found: DOUBLE_TYPE,
+                expected: INTEGER_TYPE,
+            }
+        );
+    }
+
+    #[test]
+    fn set_value_coerced_widens_integer_to_double() {
+        let empty = iter::empty::<&str>();
+
+        let mut float: f64 = 0.0;
+        float
+            .set_value_coerced(empty.clone(), Value::Integer(42))
+            .unwrap();
+        assert_eq!(float, 42.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn set_value_coerced_parses_strings() {
+        let empty = iter::empty::<&str>();
+
+        let mut integer: i64 = 0;
+        integer
+            .set_value_coerced(empty.clone(), Value::from("-7"))
+            .unwrap();
+        assert_eq!(integer, -7);
+
+        let got = integer
+            .set_value_coerced(empty.clone(), Value::from("not a number"))
+            .unwrap_err();
+        assert_eq!(
+            got,
+            UpdateError::TypeError {
+                found: STRING_TYPE,
+                expected: INTEGER_TYPE,
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn set_value_coerced_stringifies_numbers() {
+        let empty = iter::empty::<&str>();
+
+        let mut string = String::new();
+        string
+            .set_value_coerced(empty.clone(), Value::Integer(42))
+            .unwrap();
+        assert_eq!(string, "42");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn set_value_coerced_parses_strings_into_fixed_width_types() {
+        let empty = iter::empty::<&str>();
+
+        let mut byte: u8 = 0;
+        byte.set_value_coerced(empty.clone(), Value::from("200"))
+            .unwrap();
+        assert_eq!(byte, 200);
+
+        let got = byte
+            .set_value_coerced(empty.clone(), Value::from("256"))
+            .unwrap_err();
+        assert_eq!(
+            got,
+            UpdateError::TypeError {
+                found: STRING_TYPE,
+                expected: U8_TYPE,
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn suggest_field_finds_a_close_typo() {
+        let valid_fields: &'static [&'static str] = &["left", "right"];
+
+        // "lift" is one substitution away from "left" (distance 1), which
+        // just meets the max(1, len/3) == 1 threshold for a 4-letter field.
+        assert_eq!(suggest_field("lift", valid_fields), Some("left"));
+        assert_eq!(suggest_field("completely_unrelated", valid_fields), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn vec_paths_include_concrete_indices() {
+        let numbers: Vec<i64> = vec![1, 2, 3];
+
+        let got = numbers.paths();
+        assert_eq!(
+            got,
+            vec![
+                (String::from("0"), INTEGER_TYPE),
+                (String::from("1"), INTEGER_TYPE),
+                (String::from("2"), INTEGER_TYPE),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn get_and_set_use_the_path_parser() {
+        let mut numbers: Vec<i64> = vec![1, 2, 3];
+
+        numbers.set("[1]", Value::from(42)).unwrap();
+        assert_eq!(numbers, vec![1, 42, 3]);
+
+        let got = numbers.get("[1]").unwrap();
+        assert_eq!(got, Value::from(42));
+    }
+
+    #[test]
+    fn narrow_float_converts_through_double() {
+        let empty = iter::empty::<&str>();
+
+        let mut small: f32 = 0.0;
+        small.set_value(empty.clone(), Value::Double(3.5)).unwrap();
+        assert_eq!(small, 3.5);
+
+        let got = small.get_value(empty.clone()).unwrap();
+        assert_eq!(got, Value::Double(3.5));
+    }
 }
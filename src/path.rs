@@ -0,0 +1,197 @@
+//! A small parser for the path-expression syntax accepted by
+//! [`StringlyTyped::get`] and [`StringlyTyped::set`].
+//!
+//! Paths are `.`-separated field names, with `[..]` used to index into a
+//! sequence (`list[0]`, chained as `matrix[0][1]`) and single or double
+//! quotes used to escape a field name that itself contains a `.`, `[`, `]`,
+//! or space (`root."weird.key".value`). Inside a quoted field, `\"` and `\\`
+//! are recognised as escapes.
+
+use std::mem;
+use std::string::String;
+use std::vec::Vec;
+
+/// One step along a parsed path: either a named field or a sequence index.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+impl PathSegment {
+    /// The key this segment corresponds to when walking a
+    /// [`StringlyTyped`](::StringlyTyped) value, as accepted by
+    /// `get_value`/`set_value`.
+    pub fn to_key(&self) -> String {
+        match *self {
+            PathSegment::Field(ref name) => name.clone(),
+            PathSegment::Index(index) => index.to_string(),
+        }
+    }
+}
+
+/// Something went wrong while parsing a path expression.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PathError {
+    /// A `"` or `'` was opened but never closed.
+    UnterminatedQuote,
+    /// A `[` was opened but never closed.
+    UnterminatedBracket,
+    /// The text between `[` and `]` wasn't a valid `usize`.
+    InvalidIndex,
+    /// A `]` was seen without a matching `[`.
+    UnexpectedCloseBracket,
+}
+
+/// Parse a path expression like `inner.list[0].key` or
+/// `root."weird.key".value` into a sequence of [`PathSegment`]s.
+pub fn parse_path(path: &str) -> Result<Vec<PathSegment>, PathError> {
+    let mut segments = Vec::new();
+    let mut buffer = String::new();
+    let mut quote: Option<char> = None;
+    let mut in_bracket = false;
+    // Set right after a `]`, so the `.` in `list[0].key` doesn't emit an
+    // empty field.
+    let mut after_index = false;
+    let mut chars = path.chars();
+
+    while let Some(c) = chars.next() {
+        if let Some(quote_char) = quote {
+            match c {
+                '\\' => match chars.next() {
+                    Some(escaped @ '"') | Some(escaped @ '\'') | Some(escaped @ '\\') => {
+                        buffer.push(escaped);
+                    }
+                    Some(other) => {
+                        buffer.push('\\');
+                        buffer.push(other);
+                    }
+                    None => return Err(PathError::UnterminatedQuote),
+                },
+                c if c == quote_char => quote = None,
+                c => buffer.push(c),
+            }
+            continue;
+        }
+
+        let closed_index = mem::replace(&mut after_index, false);
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '.' if !in_bracket => {
+                if !(closed_index && buffer.is_empty()) {
+                    segments.push(PathSegment::Field(mem::replace(&mut buffer, String::new())));
+                }
+            }
+            '[' if !in_bracket => {
+                if !buffer.is_empty() {
+                    segments.push(PathSegment::Field(mem::replace(&mut buffer, String::new())));
+                }
+                in_bracket = true;
+            }
+            ']' if in_bracket => {
+                let index = buffer
+                    .parse()
+                    .map_err(|_| PathError::InvalidIndex)?;
+                segments.push(PathSegment::Index(index));
+                buffer.clear();
+                in_bracket = false;
+                after_index = true;
+            }
+            ']' => return Err(PathError::UnexpectedCloseBracket),
+            c => buffer.push(c),
+        }
+    }
+
+    if quote.is_some() {
+        return Err(PathError::UnterminatedQuote);
+    }
+    if in_bracket {
+        return Err(PathError::UnterminatedBracket);
+    }
+    if !buffer.is_empty() {
+        segments.push(PathSegment::Field(buffer));
+    }
+
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_dotted_path() {
+        let got = parse_path("inner.x").unwrap();
+        assert_eq!(
+            got,
+            vec![
+                PathSegment::Field(String::from("inner")),
+                PathSegment::Field(String::from("x")),
+            ]
+        );
+    }
+
+    #[test]
+    fn bracketed_index() {
+        let got = parse_path("inner.list[0].key").unwrap();
+        assert_eq!(
+            got,
+            vec![
+                PathSegment::Field(String::from("inner")),
+                PathSegment::Field(String::from("list")),
+                PathSegment::Index(0),
+                PathSegment::Field(String::from("key")),
+            ]
+        );
+    }
+
+    #[test]
+    fn chained_indices() {
+        let got = parse_path("matrix[0][1]").unwrap();
+        assert_eq!(
+            got,
+            vec![
+                PathSegment::Field(String::from("matrix")),
+                PathSegment::Index(0),
+                PathSegment::Index(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn quoted_field_with_a_dot_in_it() {
+        let got = parse_path("root.\"weird.key\".value").unwrap();
+        assert_eq!(
+            got,
+            vec![
+                PathSegment::Field(String::from("root")),
+                PathSegment::Field(String::from("weird.key")),
+                PathSegment::Field(String::from("value")),
+            ]
+        );
+    }
+
+    #[test]
+    fn quoted_field_with_escapes() {
+        let got = parse_path("'it\\'s \\\\ here'").unwrap();
+        assert_eq!(got, vec![PathSegment::Field(String::from("it's \\ here"))]);
+    }
+
+    #[test]
+    fn detects_unterminated_bracket() {
+        let got = parse_path("list[0").unwrap_err();
+        assert_eq!(got, PathError::UnterminatedBracket);
+    }
+
+    #[test]
+    fn detects_unterminated_quote() {
+        let got = parse_path("\"oops").unwrap_err();
+        assert_eq!(got, PathError::UnterminatedQuote);
+    }
+
+    #[test]
+    fn detects_non_numeric_index() {
+        let got = parse_path("list[oops]").unwrap_err();
+        assert_eq!(got, PathError::InvalidIndex);
+    }
+}
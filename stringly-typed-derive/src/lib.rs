@@ -1,6 +1,6 @@
 //! A custom derive for the [stringly-typed] crate.
-//! 
-//! [stringly-typed]: 
+//!
+//! [stringly-typed]:
 
 #![recursion_limit="256"]
 
@@ -11,15 +11,31 @@ extern crate synstructure;
 #[macro_use]
 extern crate quote;
 
-use syn::{Ident, DeriveInput};
+use syn::{Data, Ident};
 use quote::Tokens;
-use synstructure::Structure;
+use synstructure::{BindStyle, Structure};
 
 decl_derive!([StringlyTyped] => stringly_typed);
 
 const INVALID_FIELD_ERROR: &'static str = "StringlyTyped can only be derived on normal struct fields";
 
 fn stringly_typed(s: Structure) -> quote::Tokens {
+    match s.ast().data {
+        Data::Enum(_) => stringly_typed_enum(s),
+        _ => stringly_typed_struct(s),
+    }
+}
+
+/// The key a field is addressed by: its name for a named field, or its
+/// positional index (stringified) for a tuple field.
+fn field_key(index: usize, ident: &Option<Ident>) -> String {
+    match *ident {
+        Some(ref ident) => ident.to_string(),
+        None => index.to_string(),
+    }
+}
+
+fn stringly_typed_struct(s: Structure) -> quote::Tokens {
     let name = &s.ast().ident;
     let name_as_str = name.to_string();
 
@@ -30,21 +46,21 @@ fn stringly_typed(s: Structure) -> quote::Tokens {
 
     let set_body = field_names.iter().map(|name| {
         let name_as_str = name.to_string();
-        quote!(#name_as_str => self.#name.set(keys, value),)
+        quote!(#name_as_str => self.#name.set_value(keys, value),)
     })
     .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
 
     let get_body = field_names.iter().map(|name| {
         let name_as_str = name.to_string();
-        quote!(#name_as_str => self.#name.get(keys),)
+        quote!(#name_as_str => self.#name.get_value(keys),)
     })
     .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
 
     let field_names2 = field_names.clone();
     let impl_set = quote! {
-        fn set<K, S>(&mut self, keys: K, value: ::stringly_typed::Value) -> Result<(), ::stringly_typed::UpdateError>
+        fn set_value<K, S>(&mut self, keys: K, value: ::stringly_typed::Value) -> Result<(), ::stringly_typed::UpdateError>
         where K: IntoIterator<Item = S>,
-            S: AsRef<str> 
+            S: AsRef<str>
         {
             let mut keys = keys.into_iter();
 
@@ -53,19 +69,23 @@ fn stringly_typed(s: Structure) -> quote::Tokens {
 
             match element.as_ref() {
                 #set_body
-                _ => Err(::stringly_typed::UpdateError::UnknownField {
-                    valid_fields: &[
+                _ => {
+                    let valid_fields: &'static [&'static str] = &[
                         #( stringify!(#field_names2) ),*
-                    ]
-                })
+                    ];
+                    Err(::stringly_typed::UpdateError::UnknownField {
+                        suggestion: ::stringly_typed::suggest_field(element.as_ref(), valid_fields),
+                        valid_fields,
+                    })
+                }
             }
         }
     };
-    
+
     let impl_get = quote! {
-        fn get<K, S>(&self, keys: K) -> Result<::stringly_typed::Value, ::stringly_typed::UpdateError>
+        fn get_value<K, S>(&self, keys: K) -> Result<::stringly_typed::Value, ::stringly_typed::UpdateError>
         where K: IntoIterator<Item = S>,
-            S: AsRef<str> 
+            S: AsRef<str>,
         {
             let mut keys = keys.into_iter();
 
@@ -74,11 +94,15 @@ fn stringly_typed(s: Structure) -> quote::Tokens {
 
             match element.as_ref() {
                 #get_body
-                _ => Err(::stringly_typed::UpdateError::UnknownField {
-                    valid_fields: &[
+                _ => {
+                    let valid_fields: &'static [&'static str] = &[
                         #( stringify!(#field_names) ),*
-                    ]
-                })
+                    ];
+                    Err(::stringly_typed::UpdateError::UnknownField {
+                        suggestion: ::stringly_typed::suggest_field(element.as_ref(), valid_fields),
+                        valid_fields,
+                    })
+                }
             }
         }
     };
@@ -89,6 +113,29 @@ fn stringly_typed(s: Structure) -> quote::Tokens {
         }
     };
 
+    let paths_body = field_names.iter().map(|name| {
+        let name_as_str = name.to_string();
+        quote! {
+            for (sub_path, data_type) in self.#name.paths() {
+                let full_path = if sub_path.is_empty() {
+                    #name_as_str.to_string()
+                } else {
+                    format!("{}.{}", #name_as_str, sub_path)
+                };
+                result.push((full_path, data_type));
+            }
+        }
+    })
+    .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
+
+    let impl_paths = quote! {
+        fn paths(&self) -> Vec<(String, &'static str)> {
+            let mut result = Vec::new();
+            #paths_body
+            result
+        }
+    };
+
     quote! {
         impl ::stringly_typed::StringlyTyped for #name {
             #impl_set
@@ -96,6 +143,242 @@ fn stringly_typed(s: Structure) -> quote::Tokens {
             #impl_get
 
             #data_type
+
+            #impl_paths
+        }
+    }
+}
+
+/// Build the `*self = Enum::Variant { .. with Default::default() fields .. }`
+/// expression used to switch the active variant from `set`.
+fn construct_default_variant(name: &Ident, variant: &synstructure::VariantInfo) -> Tokens {
+    let variant_ident = &variant.ast().ident;
+    let bindings = variant.bindings();
+
+    if bindings.is_empty() {
+        quote!(#name::#variant_ident)
+    } else if bindings[0].ast().ident.is_some() {
+        let field_idents: Vec<&Ident> = bindings.iter()
+            .map(|b| b.ast().ident.as_ref().expect(INVALID_FIELD_ERROR))
+            .collect();
+        quote! {
+            #name::#variant_ident {
+                #( #field_idents: Default::default() ),*
+            }
+        }
+    } else {
+        let placeholders = bindings.iter().map(|_| quote!(Default::default()));
+        quote!(#name::#variant_ident( #(#placeholders),* ))
+    }
+}
+
+fn stringly_typed_enum(mut s: Structure) -> quote::Tokens {
+    let name = s.ast().ident.clone();
+    let name_as_str = name.to_string();
+
+    // Every variant name doubles as a "switch to this variant" command when
+    // it's the last key segment, so callers can pick a variant before
+    // drilling into its fields.
+    let switch_arms = s.variants().iter().map(|variant| {
+        let variant_name = variant.ast().ident.to_string();
+        let construct = construct_default_variant(&name, variant);
+
+        quote! {
+            #variant_name => {
+                *self = #construct;
+                return Ok(());
+            }
+        }
+    })
+    .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
+
+    s.bind_with(|_| BindStyle::RefMut);
+    let set_arms = s.variants().iter().map(|variant| {
+        let pat = variant.pat();
+        let bindings = variant.bindings();
+
+        if bindings.is_empty() {
+            quote! {
+                #pat => Err(::stringly_typed::UpdateError::UnknownField {
+                    valid_fields: &[],
+                    suggestion: None,
+                }),
+            }
+        } else {
+            let valid_fields: Vec<String> = bindings.iter().enumerate()
+                .map(|(i, b)| field_key(i, &b.ast().ident))
+                .collect();
+            let field_arms = bindings.iter().enumerate().map(|(i, binding)| {
+                let key = field_key(i, &binding.ast().ident);
+                let binding_ident = &binding.binding;
+                quote!(#key => #binding_ident.set_value(keys, value),)
+            })
+            .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
+
+            quote! {
+                #pat => match field.as_ref() {
+                    #field_arms
+                    _ => {
+                        let valid_fields: &'static [&'static str] = &[ #(#valid_fields),* ];
+                        Err(::stringly_typed::UpdateError::UnknownField {
+                            suggestion: ::stringly_typed::suggest_field(field.as_ref(), valid_fields),
+                            valid_fields,
+                        })
+                    }
+                },
+            }
+        }
+    })
+    .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
+
+    let impl_set = quote! {
+        fn set_value<K, S>(&mut self, keys: K, value: ::stringly_typed::Value) -> Result<(), ::stringly_typed::UpdateError>
+        where K: IntoIterator<Item = S>,
+            S: AsRef<str>
+        {
+            let mut keys = keys.into_iter();
+
+            let field = keys.next()
+                .ok_or_else(|| ::stringly_typed::UpdateError::NotEnoughKeys)?;
+            let rest: Vec<S> = keys.collect();
+
+            if rest.is_empty() {
+                match field.as_ref() {
+                    #switch_arms
+                    _ => {}
+                }
+            }
+
+            let mut keys = rest.into_iter();
+
+            match self {
+                #set_arms
+            }
+        }
+    };
+
+    s.bind_with(|_| BindStyle::Ref);
+    let get_arms = s.variants().iter().map(|variant| {
+        let pat = variant.pat();
+        let variant_name = variant.ast().ident.to_string();
+        let bindings = variant.bindings();
+
+        if bindings.is_empty() {
+            quote! {
+                #pat => {
+                    if keys.next().is_some() {
+                        let elements_remaning = keys.count() + 1;
+                        return Err(::stringly_typed::UpdateError::TooManyKeys {
+                            elements_remaning,
+                        });
+                    }
+                    Ok(::stringly_typed::Value::from(#variant_name.to_string()))
+                }
+            }
+        } else {
+            let valid_fields: Vec<String> = bindings.iter().enumerate()
+                .map(|(i, b)| field_key(i, &b.ast().ident))
+                .collect();
+            let field_arms = bindings.iter().enumerate().map(|(i, binding)| {
+                let key = field_key(i, &binding.ast().ident);
+                let binding_ident = &binding.binding;
+                quote!(#key => #binding_ident.get_value(keys),)
+            })
+            .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
+
+            quote! {
+                #pat => {
+                    let element = keys.next()
+                        .ok_or_else(|| ::stringly_typed::UpdateError::NotEnoughKeys)?;
+
+                    match element.as_ref() {
+                        #field_arms
+                        _ => {
+                            let valid_fields: &'static [&'static str] = &[ #(#valid_fields),* ];
+                            Err(::stringly_typed::UpdateError::UnknownField {
+                                suggestion: ::stringly_typed::suggest_field(element.as_ref(), valid_fields),
+                                valid_fields,
+                            })
+                        }
+                    }
+                }
+            }
+        }
+    })
+    .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
+
+    let impl_get = quote! {
+        fn get_value<K, S>(&self, keys: K) -> Result<::stringly_typed::Value, ::stringly_typed::UpdateError>
+        where K: IntoIterator<Item = S>,
+            S: AsRef<str>,
+        {
+            let mut keys = keys.into_iter();
+
+            match self {
+                #get_arms
+            }
+        }
+    };
+
+    let data_type = quote! {
+        fn data_type(&self) -> &'static str {
+            #name_as_str
+        }
+    };
+
+    let paths_arms = s.variants().iter().map(|variant| {
+        let pat = variant.pat();
+        let bindings = variant.bindings();
+
+        if bindings.is_empty() {
+            quote! {
+                #pat => vec![(String::new(), #name_as_str)],
+            }
+        } else {
+            let field_paths = bindings.iter().enumerate().map(|(i, binding)| {
+                let key = field_key(i, &binding.ast().ident);
+                let binding_ident = &binding.binding;
+                quote! {
+                    for (sub_path, data_type) in #binding_ident.paths() {
+                        let full_path = if sub_path.is_empty() {
+                            #key.to_string()
+                        } else {
+                            format!("{}.{}", #key, sub_path)
+                        };
+                        result.push((full_path, data_type));
+                    }
+                }
+            })
+            .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
+
+            quote! {
+                #pat => {
+                    let mut result = Vec::new();
+                    #field_paths
+                    result
+                },
+            }
+        }
+    })
+    .fold(Tokens::new(), |mut acc, elem| {acc.append_all(elem); acc});
+
+    let impl_paths = quote! {
+        fn paths(&self) -> Vec<(String, &'static str)> {
+            match self {
+                #paths_arms
+            }
+        }
+    };
+
+    quote! {
+        impl ::stringly_typed::StringlyTyped for #name {
+            #impl_set
+
+            #impl_get
+
+            #data_type
+
+            #impl_paths
         }
     }
 }
@@ -2,7 +2,7 @@
 #[macro_use]
 extern crate stringly_typed;
 
-use stringly_typed::{StringlyTyped, UpdateError};
+use stringly_typed::{StringlyTyped, UpdateError, Value};
 
 #[derive(StringlyTyped, Debug, Clone, PartialEq, Default)]
 struct Outer {
@@ -15,21 +15,103 @@ struct Inner {
   y: i64,
 }
 
-// TODO: Add enum support
-// #[derive(StringlyTyped, Debug, Clone, PartialEq, Default)]
-// enum Enum {
-//   First(u64),
-//   Second(Inner),
-//   Third {
-//     left: u64,
-//     right: Inner,
-//   }
-// }
+#[derive(StringlyTyped, Debug, Clone, PartialEq)]
+enum Enum {
+  First(u64),
+  Second(Inner),
+  Third {
+    left: u64,
+    right: Inner,
+  }
+}
+
+impl Default for Enum {
+  fn default() -> Enum {
+    Enum::First(0)
+  }
+}
+
+#[test]
+fn enum_dispatches_to_the_active_variant() {
+  let mut thing = Enum::First(42);
+
+  let got = thing.get("0").unwrap();
+  assert_eq!(got, Value::from(42i64));
+
+  thing.set("0", Value::from(7i64)).unwrap();
+  assert_eq!(thing, Enum::First(7));
+}
+
+#[test]
+fn enum_can_switch_variants_before_drilling_in() {
+  let mut thing = Enum::First(42);
+
+  thing.set("Third", Value::Integer(0)).unwrap();
+  assert_eq!(
+    thing,
+    Enum::Third {
+      left: 0,
+      right: Inner::default(),
+    }
+  );
+
+  thing.set("left", Value::from(99i64)).unwrap();
+  assert_eq!(
+    thing,
+    Enum::Third {
+      left: 99,
+      right: Inner::default(),
+    }
+  );
+}
+
+#[test]
+fn enumerate_every_addressable_path() {
+  let thing = Outer {
+    inner: Inner { x: 3.14, y: 42 },
+  };
+
+  let mut paths = thing.paths();
+  paths.sort();
+  assert_eq!(
+    paths,
+    vec![
+      (String::from("inner.x"), "double"),
+      (String::from("inner.y"), "integer"),
+    ]
+  );
+}
+
+#[test]
+fn unknown_field_suggests_the_closest_typo() {
+  let thing = Inner::default();
+
+  let err = thing.get("z").unwrap_err();
+  assert_eq!(
+    err,
+    UpdateError::UnknownField {
+      valid_fields: &["x", "y"],
+      suggestion: Some("x"),
+    }
+  );
+
+  let err = thing.get("not_even_close").unwrap_err();
+  assert_eq!(
+    err,
+    UpdateError::UnknownField {
+      valid_fields: &["x", "y"],
+      suggestion: None,
+    }
+  );
+}
 
 #[test]
 fn detect_when_key_is_too_short() {
   let thing = Outer::default();
-  
+
+  // "inner" only gets us to a composite field, not a leaf, so there's no
+  // key left to pick which of `Inner`'s fields to read -- the same
+  // `NotEnoughKeys` a `Vec`/map impl reports when it's indexed with no key.
   let err = thing.get("inner").unwrap_err();
-  assert_eq!(err, UpdateError::CantSerialize { data_type: "Inner" });
+  assert_eq!(err, UpdateError::NotEnoughKeys);
 }
\ No newline at end of file